@@ -12,26 +12,168 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::{collections::HashMap, sync::Arc, time::Duration};
+use std::{
+    collections::HashMap,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
-use arrow::{datatypes::Schema, ffi_stream::ArrowArrayStreamReader, pyarrow::FromPyArrow};
+use arrow::{
+    datatypes::Schema,
+    ffi_stream::ArrowArrayStreamReader,
+    ipc::writer::StreamWriter,
+    pyarrow::{FromPyArrow, PyArrowType},
+    record_batch::{RecordBatch, RecordBatchIterator, RecordBatchReader},
+};
+use async_trait::async_trait;
+use aws_sdk_sts::Client as StsClient;
+use datafusion::{catalog::schema::SchemaProvider, datasource::TableProvider, prelude::SessionContext};
+use futures::TryStreamExt;
 use lancedb::connection::{Connection as LanceConnection, CreateTableMode};
+use object_store::aws::AwsCredential;
+use object_store::{CredentialProvider, Result as ObjectStoreResult};
 use pyo3::{
     exceptions::{PyRuntimeError, PyValueError},
-    pyclass, pyfunction, pymethods, PyAny, PyRef, PyResult, Python,
+    pyclass, pyfunction, pymethods,
+    types::PyDict,
+    Py, PyAny, PyRef, PyRefMut, PyResult, Python,
 };
 use pyo3_asyncio::tokio::future_into_py;
+use tokio::sync::{Mutex, RwLock};
 
 use crate::{error::PythonErrorExt, table::Table};
 
+/// Credentials are refreshed this far ahead of their stated expiry, so a
+/// request already in flight never gets caught holding a token the server
+/// just rejected as expired.
+const CREDENTIAL_REFRESH_SKEW: Duration = Duration::from_secs(5 * 60);
+
+/// A [`CredentialProvider`] backed by a Python coroutine, for anything
+/// `connect()` doesn't have a first-class parameter for — an SSO session, a
+/// vault-issued token, or whatever else a caller's environment needs.
+///
+/// The coroutine is awaited as `await callback()` and must return a dict
+/// with `key_id`, `secret_key`, an optional `token`, and `expires_in_secs`;
+/// the result is cached until shortly before it expires.
+struct PyCredentialProvider {
+    callback: Py<PyAny>,
+    cache: RwLock<Option<(Arc<AwsCredential>, Instant)>>,
+    refresh_lock: Mutex<()>,
+}
+
+impl std::fmt::Debug for PyCredentialProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PyCredentialProvider").finish()
+    }
+}
+
+impl PyCredentialProvider {
+    fn new(callback: Py<PyAny>) -> Self {
+        Self {
+            callback,
+            cache: RwLock::new(None),
+            refresh_lock: Mutex::new(()),
+        }
+    }
+
+    async fn cached_if_fresh(&self) -> Option<Arc<AwsCredential>> {
+        let cache = self.cache.read().await;
+        match &*cache {
+            Some((credential, expires_at))
+                if Instant::now() + CREDENTIAL_REFRESH_SKEW < *expires_at =>
+            {
+                Some(Arc::clone(credential))
+            }
+            _ => None,
+        }
+    }
+
+    fn generic_err(source: impl std::error::Error + Send + Sync + 'static) -> object_store::Error {
+        object_store::Error::Generic {
+            store: "PyCredentialProvider",
+            source: Box::new(source),
+        }
+    }
+
+    async fn refresh(&self) -> ObjectStoreResult<Arc<AwsCredential>> {
+        // Single-flight: only one task actually awaits the callback at a
+        // time, the rest just re-check the cache once they get the lock.
+        let _guard = self.refresh_lock.lock().await;
+        if let Some(credential) = self.cached_if_fresh().await {
+            return Ok(credential);
+        }
+
+        let future = Python::with_gil(|py| {
+            let coro = self.callback.call0(py)?;
+            pyo3_asyncio::tokio::into_future(coro.as_ref(py))
+        })
+        .map_err(Self::generic_err)?;
+        let result = future.await.map_err(Self::generic_err)?;
+
+        let (credential, expires_at) = Python::with_gil(|py| -> PyResult<_> {
+            let dict = result.downcast::<PyDict>(py)?;
+            let key_id: String = dict
+                .get_item("key_id")?
+                .ok_or_else(|| PyValueError::new_err("credential callback missing `key_id`"))?
+                .extract()?;
+            let secret_key: String = dict
+                .get_item("secret_key")?
+                .ok_or_else(|| PyValueError::new_err("credential callback missing `secret_key`"))?
+                .extract()?;
+            let token: Option<String> = dict
+                .get_item("token")?
+                .and_then(|v| v.extract().ok());
+            let expires_in_secs: f64 = dict
+                .get_item("expires_in_secs")?
+                .ok_or_else(|| {
+                    PyValueError::new_err("credential callback missing `expires_in_secs`")
+                })?
+                .extract()?;
+
+            Ok((
+                AwsCredential {
+                    key_id,
+                    secret_key,
+                    token,
+                },
+                Instant::now() + Duration::from_secs_f64(expires_in_secs),
+            ))
+        })
+        .map_err(Self::generic_err)?;
+
+        let credential = Arc::new(credential);
+        *self.cache.write().await = Some((Arc::clone(&credential), expires_at));
+        Ok(credential)
+    }
+}
+
+#[async_trait]
+impl CredentialProvider for PyCredentialProvider {
+    type Credential = AwsCredential;
+
+    async fn get_credential(&self) -> ObjectStoreResult<Arc<AwsCredential>> {
+        if let Some(credential) = self.cached_if_fresh().await {
+            return Ok(credential);
+        }
+        self.refresh().await
+    }
+}
+
 #[pyclass]
 pub struct Connection {
     inner: Option<LanceConnection>,
+    // Interim home for per-table quotas until they have core support (see
+    // the comment above `TableQuota`) and can be read back from table
+    // metadata instead of tracked alongside the connection.
+    quotas: std::sync::Mutex<HashMap<String, TableQuota>>,
 }
 
 impl Connection {
     pub(crate) fn new(inner: LanceConnection) -> Self {
-        Self { inner: Some(inner) }
+        Self {
+            inner: Some(inner),
+            quotas: std::sync::Mutex::new(HashMap::new()),
+        }
     }
 
     fn get_inner(&self) -> PyResult<&LanceConnection> {
@@ -52,6 +194,153 @@ impl Connection {
     }
 }
 
+// KNOWN LIMITATION: `lancedb-core` has no notion of per-table quotas yet,
+// and append/merge-insert go through `Table` (not `Connection`), which
+// this binding doesn't own the write path of. So quota enforcement here
+// only covers `create_table`'s initial batch -- a table can still grow
+// past its quota via a later append or merge-insert. `max_rows`/
+// `max_bytes` are written to `storage_options` as reserved keys in case a
+// future `Table`-level write path wants to pick them up, but nothing
+// reads them back out today: `Connection.table_quota`/`set_table_quota`
+// only consult the in-process `quotas` map below, so a quota set here is
+// invisible to another `Connection` (including after a reconnect). Both
+// gaps require surfacing quota data through `Table`'s write path and
+// metadata, which is out of reach from `Connection` alone.
+const QUOTA_MAX_ROWS_KEY: &str = "lancedb.quota.max_rows";
+const QUOTA_MAX_BYTES_KEY: &str = "lancedb.quota.max_bytes";
+
+pyo3::create_exception!(connection, QuotaExceeded, pyo3::exceptions::PyException);
+
+/// The row/byte limits configured for a table via `max_rows`/`max_bytes`.
+#[derive(Debug, Clone, Copy, Default)]
+struct TableQuota {
+    max_rows: Option<u64>,
+    max_bytes: Option<u64>,
+}
+
+impl TableQuota {
+    fn from_options(max_rows: Option<u64>, max_bytes: Option<u64>) -> Option<Self> {
+        if max_rows.is_none() && max_bytes.is_none() {
+            return None;
+        }
+        Some(Self {
+            max_rows,
+            max_bytes,
+        })
+    }
+
+    fn into_storage_options(self, storage_options: &mut HashMap<String, String>) {
+        if let Some(max_rows) = self.max_rows {
+            storage_options.insert(QUOTA_MAX_ROWS_KEY.to_string(), max_rows.to_string());
+        }
+        if let Some(max_bytes) = self.max_bytes {
+            storage_options.insert(QUOTA_MAX_BYTES_KEY.to_string(), max_bytes.to_string());
+        }
+    }
+}
+
+/// Estimate the on-disk size of `batches` the way `dataset_byte_size`
+/// reports it for an existing table: by their Arrow IPC-encoded size,
+/// rather than `RecordBatch::get_array_memory_size`'s in-RAM, alignment-
+/// padded figure (which can be wildly larger than what ends up on disk).
+fn estimate_on_disk_bytes(batches: &[RecordBatch], schema: &Schema) -> PyResult<u64> {
+    let mut buffer = Vec::new();
+    {
+        let mut writer = StreamWriter::try_new(&mut buffer, schema)
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+        for batch in batches {
+            writer
+                .write(batch)
+                .map_err(|e| PyValueError::new_err(e.to_string()))?;
+        }
+        writer
+            .finish()
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+    }
+    Ok(buffer.len() as u64)
+}
+
+/// Reject a write whose projected row/byte counts would exceed `quota`,
+/// rather than silently letting the table grow past it.
+fn check_quota(quota: TableQuota, projected_rows: u64, projected_bytes: u64) -> PyResult<()> {
+    if let Some(max_rows) = quota.max_rows {
+        if projected_rows > max_rows {
+            return Err(QuotaExceeded::new_err(format!(
+                "write would bring the table to {projected_rows} rows, exceeding the {max_rows} row quota"
+            )));
+        }
+    }
+    if let Some(max_bytes) = quota.max_bytes {
+        if projected_bytes > max_bytes {
+            return Err(QuotaExceeded::new_err(format!(
+                "write would bring the table to {projected_bytes} bytes, exceeding the {max_bytes} byte quota"
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// A DataFusion [`SchemaProvider`] that resolves table names against a
+/// [`LanceConnection`] on demand, so `Connection::sql` only opens the
+/// tables a query actually references instead of every table in the
+/// database, and opens them through the same `open_table` builder as
+/// everything else (so the connection's `storage_options`/
+/// `read_consistency_interval` apply here too).
+struct LanceSchemaProvider {
+    connection: LanceConnection,
+}
+
+#[async_trait]
+impl SchemaProvider for LanceSchemaProvider {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn table_names(&self) -> Vec<String> {
+        // DataFusion only calls this for catalog introspection (e.g.
+        // `information_schema`), not to resolve the tables a query
+        // references, so it's fine that listing them would require an
+        // async round-trip we can't make from this sync method.
+        Vec::new()
+    }
+
+    async fn table(
+        &self,
+        name: &str,
+    ) -> datafusion::error::Result<Option<Arc<dyn TableProvider>>> {
+        let table = match self.connection.open_table(name).execute().await {
+            Ok(table) => table,
+            Err(_) => return Ok(None),
+        };
+        Ok(Some(table.dataset_table_provider().await?))
+    }
+
+    fn register_table(
+        &self,
+        name: String,
+        _table: Arc<dyn TableProvider>,
+    ) -> datafusion::error::Result<Option<Arc<dyn TableProvider>>> {
+        Err(datafusion::error::DataFusionError::Plan(format!(
+            "tables are resolved from the connection, `{name}` can't be registered directly"
+        )))
+    }
+
+    fn deregister_table(
+        &self,
+        _name: &str,
+    ) -> datafusion::error::Result<Option<Arc<dyn TableProvider>>> {
+        Ok(None)
+    }
+
+    fn table_exist(&self, _name: &str) -> bool {
+        // Only `table()` is used to actually resolve a reference; reporting
+        // every name as a candidate here just lets DataFusion's planner
+        // find out the real answer (via `table()`) instead of us doing a
+        // redundant existence check up front.
+        true
+    }
+}
+
 #[pymethods]
 impl Connection {
     fn __repr__(&self) -> String {
@@ -85,21 +374,116 @@ impl Connection {
         future_into_py(self_.py(), async move { op.execute().await.infer_error() })
     }
 
+    /// Like [`Self::table_names`], but resolves to an async generator that
+    /// yields a per-table descriptor (name, approximate row count, latest
+    /// version, total byte size, and index summary), so callers can render
+    /// a catalog without N round-trips of `open_table` + `count_rows` +
+    /// `list_indices`.
+    ///
+    /// `start_after`/`limit` page the underlying table name listing the
+    /// same way they do for `table_names`; the per-table descriptor itself
+    /// is only fetched as the generator is advanced.
+    pub fn list_tables(
+        self_: PyRef<'_, Self>,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> PyResult<&PyAny> {
+        let inner = self_.get_inner()?.clone();
+        let mut op = inner.table_names();
+        if let Some(start_after) = start_after {
+            op = op.start_after(start_after);
+        }
+        if let Some(limit) = limit {
+            op = op.limit(limit);
+        }
+        future_into_py(self_.py(), async move {
+            let names = op.execute().await.infer_error()?;
+            Ok(TableDescriptorIterator {
+                inner,
+                names: names.into_iter(),
+            })
+        })
+    }
+
+    /// Run a full SQL statement across the tables in this database.
+    ///
+    /// Each referenced table is registered lazily as a DataFusion
+    /// `TableProvider` (opened the same way `open_table` does, so it honors
+    /// `storage_options`/`read_consistency_interval`), which unlocks joins,
+    /// aggregations, and multi-table filters that would otherwise require
+    /// pulling the tables out and recombining them client-side.
+    pub fn sql<'a>(self_: PyRef<'a, Self>, query: String) -> PyResult<&'a PyAny> {
+        let inner = self_.get_inner()?.clone();
+        future_into_py(self_.py(), async move {
+            let ctx = SessionContext::new();
+            ctx.catalog("datafusion")
+                .expect("default catalog is always present")
+                .register_schema(
+                    "public",
+                    Arc::new(LanceSchemaProvider { connection: inner }),
+                )
+                .infer_error()?;
+
+            let df = ctx.sql(&query).await.infer_error()?;
+            let schema = Arc::new(Schema::from(df.schema()));
+            let batches: Vec<_> = df
+                .execute_stream()
+                .await
+                .infer_error()?
+                .try_collect()
+                .await
+                .infer_error()?;
+
+            let reader = RecordBatchIterator::new(batches.into_iter().map(Ok), schema);
+            Ok(PyArrowType(Box::new(reader) as Box<dyn RecordBatchReader + Send>))
+        })
+    }
+
+    #[pyo3(signature = (name, mode, data, storage_options = None, max_rows = None, max_bytes = None))]
     pub fn create_table<'a>(
         self_: PyRef<'a, Self>,
         name: String,
         mode: &str,
         data: &PyAny,
         storage_options: Option<HashMap<String, String>>,
+        max_rows: Option<u64>,
+        max_bytes: Option<u64>,
     ) -> PyResult<&'a PyAny> {
         let inner = self_.get_inner()?.clone();
 
         let mode = Self::parse_create_mode_str(mode)?;
 
-        let batches = ArrowArrayStreamReader::from_pyarrow(data)?;
-        let mut builder = inner.create_table(name, batches).mode(mode);
+        let reader = ArrowArrayStreamReader::from_pyarrow(data)?;
+        let quota = TableQuota::from_options(max_rows, max_bytes);
 
-        if let Some(storage_options) = storage_options {
+        if let Some(quota) = quota {
+            self_.quotas.lock().unwrap().insert(name.clone(), quota);
+        }
+        let mut storage_options = storage_options.unwrap_or_default();
+        if let Some(quota) = quota {
+            quota.into_storage_options(&mut storage_options);
+        }
+
+        // Only the quota'd path needs to materialize the reader up front
+        // (to check the projected totals before anything is written); the
+        // common no-quota path keeps streaming straight through to
+        // `create_table` instead of buffering the whole ingest in memory.
+        let reader: Box<dyn RecordBatchReader + Send> = if let Some(quota) = quota {
+            let schema = reader.schema();
+            let batches = reader
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|e| PyValueError::new_err(e.to_string()))?;
+            let projected_rows: u64 = batches.iter().map(|b| b.num_rows() as u64).sum();
+            let projected_bytes = estimate_on_disk_bytes(&batches, &schema)?;
+            check_quota(quota, projected_rows, projected_bytes)?;
+
+            Box::new(RecordBatchIterator::new(batches.into_iter().map(Ok), schema))
+        } else {
+            Box::new(reader)
+        };
+
+        let mut builder = inner.create_table(name, reader).mode(mode);
+        if !storage_options.is_empty() {
             builder = builder.storage_options(storage_options);
         }
 
@@ -109,22 +493,33 @@ impl Connection {
         })
     }
 
+    #[pyo3(signature = (name, mode, schema, storage_options = None, max_rows = None, max_bytes = None))]
     pub fn create_empty_table<'a>(
         self_: PyRef<'a, Self>,
         name: String,
         mode: &str,
         schema: &PyAny,
         storage_options: Option<HashMap<String, String>>,
+        max_rows: Option<u64>,
+        max_bytes: Option<u64>,
     ) -> PyResult<&'a PyAny> {
         let inner = self_.get_inner()?.clone();
 
         let mode = Self::parse_create_mode_str(mode)?;
 
         let schema = Schema::from_pyarrow(schema)?;
+        let quota = TableQuota::from_options(max_rows, max_bytes);
+        if let Some(quota) = quota {
+            self_.quotas.lock().unwrap().insert(name.clone(), quota);
+        }
 
         let mut builder = inner.create_empty_table(name, Arc::new(schema)).mode(mode);
 
-        if let Some(storage_options) = storage_options {
+        let mut storage_options = storage_options.unwrap_or_default();
+        if let Some(quota) = quota {
+            quota.into_storage_options(&mut storage_options);
+        }
+        if !storage_options.is_empty() {
             builder = builder.storage_options(storage_options);
         }
 
@@ -169,9 +564,198 @@ impl Connection {
             async move { inner.drop_db().await.infer_error() },
         )
     }
+
+    /// Return the `(max_rows, max_bytes)` quota set on a table, if any.
+    ///
+    /// This only sees quotas set by `create_table`/`create_empty_table`/
+    /// `set_table_quota` on *this* `Connection` instance (see the comment
+    /// above `TableQuota` for why), not ones persisted by another process.
+    pub fn table_quota(self_: PyRef<'_, Self>, name: String) -> PyResult<&PyAny> {
+        let quota = self_.quotas.lock().unwrap().get(&name).copied();
+        future_into_py(self_.py(), async move {
+            Ok(quota.map(|q| (q.max_rows, q.max_bytes)))
+        })
+    }
+
+    #[pyo3(signature = (name, max_rows = None, max_bytes = None))]
+    pub fn set_table_quota(
+        self_: PyRef<'_, Self>,
+        name: String,
+        max_rows: Option<u64>,
+        max_bytes: Option<u64>,
+    ) -> PyResult<&PyAny> {
+        match TableQuota::from_options(max_rows, max_bytes) {
+            Some(quota) => {
+                self_.quotas.lock().unwrap().insert(name, quota);
+            }
+            None => {
+                self_.quotas.lock().unwrap().remove(&name);
+            }
+        }
+        future_into_py(self_.py(), async move { Ok(()) })
+    }
+}
+
+/// Backs the async generator returned by [`Connection::list_tables`]. The
+/// table name page has already been fetched; each `__anext__` call opens
+/// the next table and collects its descriptor.
+#[pyclass]
+pub struct TableDescriptorIterator {
+    inner: LanceConnection,
+    names: std::vec::IntoIter<String>,
+}
+
+#[pymethods]
+impl TableDescriptorIterator {
+    fn __aiter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __anext__<'a>(mut slf: PyRefMut<'a, Self>, py: Python<'a>) -> PyResult<&'a PyAny> {
+        let Some(name) = slf.names.next() else {
+            return Err(pyo3::exceptions::PyStopAsyncIteration::new_err(()));
+        };
+
+        let inner = slf.inner.clone();
+        future_into_py(py, async move {
+            let table = inner.open_table(&name).execute().await.infer_error()?;
+            // Fetch the four descriptor fields concurrently rather than as
+            // four sequential round-trips -- wall-clock for one table's
+            // descriptor becomes the slowest single field instead of the
+            // sum of all of them.
+            let (row_count, version, num_bytes, indices) = tokio::try_join!(
+                table.count_rows(None),
+                table.version(),
+                table.dataset_byte_size(),
+                table.list_indices(),
+            )
+            .infer_error()?;
+            let num_indices = indices.len();
+
+            Python::with_gil(|py| {
+                let descriptor = PyDict::new(py);
+                descriptor.set_item("name", &name)?;
+                descriptor.set_item("row_count", row_count)?;
+                descriptor.set_item("version", version)?;
+                descriptor.set_item("num_bytes", num_bytes)?;
+                descriptor.set_item("num_indices", num_indices)?;
+                Ok(descriptor.into_py(py))
+            })
+        })
+    }
+}
+
+/// Parameters needed to assume an IAM role via STS `AssumeRole`.
+struct AssumeRoleConfig {
+    role_arn: String,
+    session_name: String,
+    external_id: Option<String>,
+    duration: Duration,
+}
+
+/// A [`CredentialProvider`] that mints temporary AWS credentials via STS
+/// `AssumeRole` and transparently refreshes them before they expire, the
+/// same way the Node binding's `RefreshingCredentialProvider` does (this
+/// binding can't share that type directly since it lives in a different
+/// crate, so the single-flight cache is duplicated here to match).
+struct AssumeRoleCredentialProvider {
+    config: AssumeRoleConfig,
+    sts_client: StsClient,
+    cache: RwLock<Option<(Arc<AwsCredential>, Instant)>>,
+    refresh_lock: Mutex<()>,
+}
+
+impl std::fmt::Debug for AssumeRoleCredentialProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AssumeRoleCredentialProvider").finish()
+    }
+}
+
+impl AssumeRoleCredentialProvider {
+    fn new(sts_client: StsClient, config: AssumeRoleConfig) -> Self {
+        Self {
+            config,
+            sts_client,
+            cache: RwLock::new(None),
+            refresh_lock: Mutex::new(()),
+        }
+    }
+
+    async fn cached_if_fresh(&self) -> Option<Arc<AwsCredential>> {
+        let cache = self.cache.read().await;
+        match &*cache {
+            Some((credential, expires_at))
+                if Instant::now() + CREDENTIAL_REFRESH_SKEW < *expires_at =>
+            {
+                Some(Arc::clone(credential))
+            }
+            _ => None,
+        }
+    }
+
+    async fn refresh(&self) -> ObjectStoreResult<Arc<AwsCredential>> {
+        let _guard = self.refresh_lock.lock().await;
+        if let Some(credential) = self.cached_if_fresh().await {
+            return Ok(credential);
+        }
+
+        let response = self
+            .sts_client
+            .assume_role()
+            .role_arn(&self.config.role_arn)
+            .role_session_name(&self.config.session_name)
+            .set_external_id(self.config.external_id.clone())
+            .duration_seconds(self.config.duration.as_secs() as i32)
+            .send()
+            .await
+            .map_err(|source| object_store::Error::Generic {
+                store: "AssumeRole",
+                source: Box::new(source),
+            })?;
+        let creds = response
+            .credentials()
+            .ok_or_else(|| object_store::Error::Generic {
+                store: "AssumeRole",
+                source: "STS AssumeRole response contained no credentials".into(),
+            })?;
+
+        let credential = Arc::new(AwsCredential {
+            key_id: creds.access_key_id().to_string(),
+            secret_key: creds.secret_access_key().to_string(),
+            token: Some(creds.session_token().to_string()),
+        });
+        *self.cache.write().await =
+            Some((Arc::clone(&credential), Instant::now() + self.config.duration));
+        Ok(credential)
+    }
+}
+
+#[async_trait]
+impl CredentialProvider for AssumeRoleCredentialProvider {
+    type Credential = AwsCredential;
+
+    async fn get_credential(&self) -> ObjectStoreResult<Arc<AwsCredential>> {
+        if let Some(credential) = self.cached_if_fresh().await {
+            return Ok(credential);
+        }
+        self.refresh().await
+    }
 }
 
 #[pyfunction]
+#[pyo3(signature = (
+    uri,
+    api_key = None,
+    region = None,
+    host_override = None,
+    read_consistency_interval = None,
+    storage_options = None,
+    assume_role_arn = None,
+    assume_role_session_name = None,
+    assume_role_external_id = None,
+    assume_role_duration_secs = None,
+    aws_credential_provider = None,
+))]
 pub fn connect(
     py: Python,
     uri: String,
@@ -180,6 +764,11 @@ pub fn connect(
     host_override: Option<String>,
     read_consistency_interval: Option<f64>,
     storage_options: Option<HashMap<String, String>>,
+    assume_role_arn: Option<String>,
+    assume_role_session_name: Option<String>,
+    assume_role_external_id: Option<String>,
+    assume_role_duration_secs: Option<f64>,
+    aws_credential_provider: Option<Py<PyAny>>,
 ) -> PyResult<&PyAny> {
     future_into_py(py, async move {
         let mut builder = lancedb::connect(&uri);
@@ -196,9 +785,42 @@ pub fn connect(
             let read_consistency_interval = Duration::from_secs_f64(read_consistency_interval);
             builder = builder.read_consistency_interval(read_consistency_interval);
         }
-        if let Some(storage_options) = storage_options {
+        // `storage_options` applies regardless of which credential path (if
+        // any) is taken below — it configures the object store itself
+        // (endpoint, TLS, etc.), not just credentials.
+        let storage_options = storage_options.unwrap_or_default();
+        if !storage_options.is_empty() {
             builder = builder.storage_options(storage_options);
         }
+
+        if let Some(aws_credential_provider) = aws_credential_provider {
+            // Let a caller-supplied coroutine mint credentials (SSO, a
+            // secrets daemon, ...) instead of passing raw keys or an
+            // assume-role config through `connect()`.
+            let provider: Arc<dyn CredentialProvider<Credential = AwsCredential>> =
+                Arc::new(PyCredentialProvider::new(aws_credential_provider));
+            builder = builder.aws_credential_provider(provider);
+        } else if let Some(assume_role_arn) = assume_role_arn {
+            // Actually assume the role and keep the resulting session
+            // tokens refreshed for the life of the connection, instead of
+            // stuffing the ARN into `storage_options` where nothing reads
+            // it back out.
+            let sts_config = aws_config::load_from_env().await;
+            let config = AssumeRoleConfig {
+                role_arn: assume_role_arn,
+                session_name: assume_role_session_name.unwrap_or_else(|| "lancedb".to_string()),
+                external_id: assume_role_external_id,
+                duration: assume_role_duration_secs
+                    .map(Duration::from_secs_f64)
+                    .unwrap_or(Duration::from_secs(3600)),
+            };
+            let provider: Arc<dyn CredentialProvider<Credential = AwsCredential>> =
+                Arc::new(AssumeRoleCredentialProvider::new(
+                    StsClient::new(&sts_config),
+                    config,
+                ));
+            builder = builder.aws_credential_provider(provider);
+        }
         Ok(Connection::new(builder.execute().await.infer_error()?))
     })
 }
@@ -0,0 +1,257 @@
+// Copyright 2023 Lance Developers.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::future::Future;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use aws_sdk_sts::Client as StsClient;
+use neon::prelude::*;
+use object_store::aws::AwsCredential;
+use object_store::{CredentialProvider, Result as ObjectStoreResult};
+use tokio::sync::{oneshot, Mutex, RwLock};
+
+/// Margin subtracted from a credential's reported expiry before it's
+/// treated as stale, so a refresh always lands before the real deadline
+/// rather than racing it.
+const REFRESH_SKEW: Duration = Duration::from_secs(5 * 60);
+
+/// A single-flight, expiry-aware cache shared by every credential provider
+/// in this module. `get_credential` implementations just need to supply a
+/// future that mints a fresh credential plus its expiry; the cache takes
+/// care of serving cached values and making sure only one in-flight refresh
+/// happens at a time.
+#[derive(Debug, Default)]
+struct ExpiringCredentialCache {
+    cache: RwLock<Option<(Arc<AwsCredential>, Instant)>>,
+    refresh_lock: Mutex<()>,
+}
+
+impl ExpiringCredentialCache {
+    async fn cached_if_fresh(&self) -> Option<Arc<AwsCredential>> {
+        let cache = self.cache.read().await;
+        match &*cache {
+            Some((credential, expires_at)) if Instant::now() + REFRESH_SKEW < *expires_at => {
+                Some(Arc::clone(credential))
+            }
+            _ => None,
+        }
+    }
+
+    async fn get_or_refresh<F, Fut>(&self, refresh: F) -> ObjectStoreResult<Arc<AwsCredential>>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = ObjectStoreResult<(AwsCredential, Instant)>>,
+    {
+        if let Some(credential) = self.cached_if_fresh().await {
+            return Ok(credential);
+        }
+
+        // Single-flight: only one task actually refreshes at a time, the
+        // rest just re-check the cache once they get the lock.
+        let _guard = self.refresh_lock.lock().await;
+        if let Some(credential) = self.cached_if_fresh().await {
+            return Ok(credential);
+        }
+
+        let (credential, expires_at) = refresh().await?;
+        let credential = Arc::new(credential);
+        *self.cache.write().await = Some((Arc::clone(&credential), expires_at));
+        Ok(credential)
+    }
+}
+
+/// Parameters needed to assume an IAM role via STS `AssumeRole`.
+#[derive(Debug, Clone)]
+pub struct AssumeRoleConfig {
+    pub role_arn: String,
+    pub session_name: String,
+    pub external_id: Option<String>,
+    pub duration: Duration,
+}
+
+/// A [`CredentialProvider`] that mints temporary AWS credentials via STS
+/// `AssumeRole` and transparently refreshes them before they expire.
+///
+/// This allows long-lived [`Connection`](lancedb::connection::Connection)s
+/// to keep working past the lifetime of a single set of session tokens,
+/// unlike `StaticCredentialProvider`, which hands back the same credential
+/// forever.
+#[derive(Debug)]
+pub struct RefreshingCredentialProvider {
+    config: AssumeRoleConfig,
+    sts_client: StsClient,
+    cache: ExpiringCredentialCache,
+}
+
+impl RefreshingCredentialProvider {
+    pub fn new(sts_client: StsClient, config: AssumeRoleConfig) -> Self {
+        Self {
+            config,
+            sts_client,
+            cache: ExpiringCredentialCache::default(),
+        }
+    }
+}
+
+#[async_trait]
+impl CredentialProvider for RefreshingCredentialProvider {
+    type Credential = AwsCredential;
+
+    async fn get_credential(&self) -> ObjectStoreResult<Arc<AwsCredential>> {
+        self.cache
+            .get_or_refresh(|| async {
+                let mut request = self
+                    .sts_client
+                    .assume_role()
+                    .role_arn(&self.config.role_arn)
+                    .role_session_name(&self.config.session_name)
+                    .duration_seconds(self.config.duration.as_secs() as i32);
+                if let Some(external_id) = &self.config.external_id {
+                    request = request.external_id(external_id);
+                }
+
+                let response =
+                    request
+                        .send()
+                        .await
+                        .map_err(|source| object_store::Error::Generic {
+                            store: "AssumeRole",
+                            source: Box::new(source),
+                        })?;
+                let creds = response
+                    .credentials()
+                    .ok_or_else(|| object_store::Error::Generic {
+                        store: "AssumeRole",
+                        source: "STS AssumeRole response contained no credentials".into(),
+                    })?;
+
+                let credential = AwsCredential {
+                    key_id: creds.access_key_id().to_string(),
+                    secret_key: creds.secret_access_key().to_string(),
+                    token: Some(creds.session_token().to_string()),
+                };
+                Ok((credential, Instant::now() + self.config.duration))
+            })
+            .await
+    }
+}
+
+/// A [`CredentialProvider`] that delegates to a JS async function, for
+/// whatever credential sources don't have a dedicated constructor argument
+/// — an SSO session, an SSH-agent-signed exchange, a company's internal
+/// secrets daemon, and so on.
+///
+/// The callback is invoked as `callback(): Promise<{keyId, secretKey,
+/// token?, expiresInSecs}>` on the JS event loop via `channel`, and its
+/// result is cached the same way `RefreshingCredentialProvider` caches STS
+/// credentials.
+pub struct CallbackCredentialProvider {
+    channel: Channel,
+    callback: Arc<Root<JsFunction>>,
+    cache: ExpiringCredentialCache,
+}
+
+impl std::fmt::Debug for CallbackCredentialProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CallbackCredentialProvider").finish()
+    }
+}
+
+impl CallbackCredentialProvider {
+    pub fn new(channel: Channel, callback: Root<JsFunction>) -> Self {
+        Self {
+            channel,
+            callback: Arc::new(callback),
+            cache: ExpiringCredentialCache::default(),
+        }
+    }
+
+    /// Invoke the JS callback and await its promise on the JS event loop,
+    /// without blocking the runtime this future is polled on.
+    ///
+    /// Both settlements are handled: a rejection is turned into an
+    /// `object_store::Error` carrying the JS error's message, instead of
+    /// leaving `rx` to hang until this provider gives up and reports a
+    /// misleading "callback was dropped" error.
+    async fn invoke(&self) -> ObjectStoreResult<(AwsCredential, Instant)> {
+        let callback = Arc::clone(&self.callback);
+        let (tx, rx) = oneshot::channel();
+
+        self.channel.send(move |mut cx| {
+            let callback = callback.to_inner(&mut cx);
+            let this = cx.undefined();
+            let result = callback.call(&mut cx, this, [])?;
+            let promise = result.downcast_or_throw::<JsPromise, _>(&mut cx)?;
+            let tx = std::sync::Mutex::new(Some(tx));
+
+            promise.to_future(&mut cx, move |mut cx, settled| {
+                let outcome: Result<(AwsCredential, Instant), String> = match settled {
+                    Ok(value) => {
+                        let value = value.downcast_or_throw::<JsObject, _>(&mut cx)?;
+                        let key_id = value
+                            .get::<JsString, _, _>(&mut cx, "keyId")?
+                            .value(&mut cx);
+                        let secret_key = value
+                            .get::<JsString, _, _>(&mut cx, "secretKey")?
+                            .value(&mut cx);
+                        let token = value
+                            .get_opt::<JsString, _, _>(&mut cx, "token")?
+                            .map(|v| v.value(&mut cx));
+                        let expires_in_secs = value
+                            .get::<JsNumber, _, _>(&mut cx, "expiresInSecs")?
+                            .value(&mut cx);
+
+                        let credential = AwsCredential {
+                            key_id,
+                            secret_key,
+                            token,
+                        };
+                        let expires_at = Instant::now() + Duration::from_secs_f64(expires_in_secs);
+                        Ok((credential, expires_at))
+                    }
+                    Err(error) => Err(error
+                        .downcast::<JsError, _>(&mut cx)
+                        .ok()
+                        .and_then(|e| e.get::<JsString, _, _>(&mut cx, "message").ok())
+                        .map(|m| m.value(&mut cx))
+                        .unwrap_or_else(|| "credential callback rejected".to_string())),
+                };
+                if let Some(tx) = tx.lock().unwrap().take() {
+                    let _ = tx.send(outcome.map_err(|message| object_store::Error::Generic {
+                        store: "CallbackCredentialProvider",
+                        source: message.into(),
+                    }));
+                }
+                Ok(())
+            })?;
+            Ok(())
+        });
+
+        rx.await.map_err(|_| object_store::Error::Generic {
+            store: "CallbackCredentialProvider",
+            source: "credential callback was dropped before it resolved".into(),
+        })?
+    }
+}
+
+#[async_trait]
+impl CredentialProvider for CallbackCredentialProvider {
+    type Credential = AwsCredential;
+
+    async fn get_credential(&self) -> ObjectStoreResult<Arc<AwsCredential>> {
+        self.cache.get_or_refresh(|| self.invoke()).await
+    }
+}
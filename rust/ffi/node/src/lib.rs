@@ -15,6 +15,7 @@
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use aws_sdk_sts::Client as StsClient;
 use lance::io::ObjectStoreParams;
 use neon::prelude::*;
 use object_store::aws::{AwsCredential, AwsCredentialProvider};
@@ -32,12 +33,17 @@ use crate::table::JsTable;
 
 mod arrow;
 mod convert;
+mod credential;
 mod error;
 mod index;
+mod listing;
 mod neon_ext;
 mod query;
+mod sql;
 mod table;
 
+use credential::{AssumeRoleConfig, CallbackCredentialProvider, RefreshingCredentialProvider};
+
 struct JsDatabase {
     database: Connection,
 }
@@ -178,16 +184,109 @@ fn get_aws_creds(
     }
 }
 
-fn get_aws_credential_provider(
+/// Get STS AssumeRole arguments from the context.
+/// Consumes 4 arguments: role ARN, session name, external ID, duration (seconds).
+///
+/// Only `role_arn` is required; the rest fall back to sensible defaults so
+/// callers can opt in without specifying every field.
+fn get_assume_role_config(
     cx: &mut FunctionContext,
     arg_starting_location: i32,
-) -> NeonResult<Option<AwsCredentialProvider>> {
-    Ok(get_aws_creds(cx, arg_starting_location)?.map(|aws_cred| {
-        Arc::new(StaticCredentialProvider::new(aws_cred))
-            as Arc<dyn CredentialProvider<Credential = AwsCredential>>
+) -> NeonResult<Option<AssumeRoleConfig>> {
+    let role_arn = cx
+        .argument_opt(arg_starting_location)
+        .filter(|arg| arg.is_a::<JsString, _>(cx))
+        .and_then(|arg| arg.downcast_or_throw::<JsString, FunctionContext>(cx).ok())
+        .map(|v| v.value(cx));
+    let Some(role_arn) = role_arn else {
+        return Ok(None);
+    };
+
+    let session_name = cx
+        .argument_opt(arg_starting_location + 1)
+        .filter(|arg| arg.is_a::<JsString, _>(cx))
+        .and_then(|arg| arg.downcast_or_throw::<JsString, FunctionContext>(cx).ok())
+        .map(|v| v.value(cx))
+        .unwrap_or_else(|| "lancedb".to_string());
+
+    let external_id = cx
+        .argument_opt(arg_starting_location + 2)
+        .filter(|arg| arg.is_a::<JsString, _>(cx))
+        .and_then(|arg| arg.downcast_or_throw::<JsString, FunctionContext>(cx).ok())
+        .map(|v| v.value(cx));
+
+    let duration = cx
+        .argument_opt(arg_starting_location + 3)
+        .and_then(|arg| arg.downcast::<JsNumber, _>(cx).ok())
+        .map(|v| v.value(cx))
+        .map(std::time::Duration::from_secs_f64)
+        .unwrap_or(std::time::Duration::from_secs(3600));
+
+    Ok(Some(AssumeRoleConfig {
+        role_arn,
+        session_name,
+        external_id,
+        duration,
     }))
 }
 
+/// Either a fixed credential pair, an STS AssumeRole flow, or a caller-
+/// supplied JS callback, resolved to an actual [`AwsCredentialProvider`]
+/// once we're on the async runtime (assuming a role requires talking to
+/// STS, and the callback needs a [`Channel`] back to the JS event loop).
+enum AwsCredentialSource {
+    Static(AwsCredential),
+    AssumeRole(AssumeRoleConfig),
+    Callback(Root<JsFunction>),
+}
+
+impl AwsCredentialSource {
+    async fn into_provider(self, channel: Channel) -> AwsCredentialProvider {
+        match self {
+            Self::Static(cred) => Arc::new(StaticCredentialProvider::new(cred)),
+            Self::AssumeRole(config) => {
+                let sts_config = aws_config::load_from_env().await;
+                Arc::new(RefreshingCredentialProvider::new(
+                    StsClient::new(&sts_config),
+                    config,
+                ))
+            }
+            Self::Callback(callback) => {
+                Arc::new(CallbackCredentialProvider::new(channel, callback))
+            }
+        }
+    }
+}
+
+/// Get a caller-supplied async credential callback, for credential sources
+/// we don't know how to talk to natively (an SSO session, a secrets
+/// daemon, ...). Consumes a single argument.
+fn get_credential_callback(
+    cx: &mut FunctionContext,
+    arg_location: i32,
+) -> NeonResult<Option<Root<JsFunction>>> {
+    Ok(cx
+        .argument_opt(arg_location)
+        .filter(|arg| arg.is_a::<JsFunction, _>(cx))
+        .and_then(|arg| arg.downcast_or_throw::<JsFunction, FunctionContext>(cx).ok())
+        .map(|f| f.root(cx)))
+}
+
+fn get_aws_credential_source(
+    cx: &mut FunctionContext,
+    arg_starting_location: i32,
+    assume_role_location: i32,
+    callback_location: i32,
+) -> NeonResult<Option<AwsCredentialSource>> {
+    if let Some(callback) = get_credential_callback(cx, callback_location)? {
+        return Ok(Some(AwsCredentialSource::Callback(callback)));
+    }
+    if let Some(assume_role) = get_assume_role_config(cx, assume_role_location)? {
+        return Ok(Some(AwsCredentialSource::AssumeRole(assume_role)));
+    }
+    Ok(get_aws_creds(cx, arg_starting_location)?.map(AwsCredentialSource::Static))
+}
+
 /// Get AWS region arguments from the context
 fn get_aws_region(cx: &mut FunctionContext, arg_location: i32) -> NeonResult<Option<String>> {
     let region = cx
@@ -208,23 +307,27 @@ fn database_open_table(mut cx: FunctionContext) -> JsResult<JsPromise> {
         .downcast_or_throw::<JsBox<JsDatabase>, _>(&mut cx)?;
     let table_name = cx.argument::<JsString>(0)?.value(&mut cx);
 
-    let aws_creds = get_aws_credential_provider(&mut cx, 1)?;
+    let aws_creds_source = get_aws_credential_source(&mut cx, 1, 5, 9)?;
 
     let aws_region = get_aws_region(&mut cx, 4)?;
 
-    let params = ReadParams {
-        store_options: Some(ObjectStoreParams::with_aws_credentials(
-            aws_creds, aws_region,
-        )),
-        ..ReadParams::default()
-    };
-
     let rt = runtime(&mut cx)?;
     let channel = cx.channel();
     let database = db.database.clone();
 
     let (deferred, promise) = cx.promise();
+    let provider_channel = channel.clone();
     rt.spawn(async move {
+        let aws_creds = match aws_creds_source {
+            Some(source) => Some(source.into_provider(provider_channel).await),
+            None => None,
+        };
+        let params = ReadParams {
+            store_options: Some(ObjectStoreParams::with_aws_credentials(
+                aws_creds, aws_region,
+            )),
+            ..ReadParams::default()
+        };
         let table_rst = database
             .open_table(&table_name)
             .lance_read_params(params)
@@ -266,6 +369,8 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
     cx.export_function("databaseTableNames", database_table_names)?;
     cx.export_function("databaseOpenTable", database_open_table)?;
     cx.export_function("databaseDropTable", database_drop_table)?;
+    cx.export_function("connectionSql", sql::database_sql)?;
+    cx.export_function("connectionListTables", listing::database_list_tables)?;
     cx.export_function("tableSearch", JsQuery::js_search)?;
     cx.export_function("tableCreate", JsTable::js_create)?;
     cx.export_function("tableAdd", JsTable::js_add)?;
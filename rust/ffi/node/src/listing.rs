@@ -0,0 +1,133 @@
+// Copyright 2024 Lance Developers.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use futures::stream::{self, StreamExt, TryStreamExt};
+use lancedb::connection::Connection;
+use neon::prelude::*;
+
+use crate::error::ResultExt;
+
+/// How many tables' descriptors to fetch at once. Each table is a handful
+/// of independent object-store round-trips, so fetching several tables at
+/// a time (rather than one after another) cuts `connectionListTables`'s
+/// wall-clock time to roughly that of one lookup per batch of this many
+/// tables.
+const DESCRIPTOR_CONCURRENCY: usize = 8;
+
+/// Open each table just long enough to gather the handful of cheap facts
+/// (row count, version, byte size, index count) that `tableNames` alone
+/// can't give callers, fetching all four concurrently so one table's
+/// descriptor costs as much as its slowest field, not the sum of all four.
+async fn table_descriptor(database: &Connection, name: &str) -> lancedb::Result<TableDescriptor> {
+    let table = database.open_table(name).execute().await?;
+    let (row_count, version, num_bytes, indices) = futures::try_join!(
+        table.count_rows(None),
+        table.version(),
+        table.dataset_byte_size(),
+        table.list_indices(),
+    )?;
+    Ok(TableDescriptor {
+        name: name.to_string(),
+        row_count: row_count as f64,
+        version: version as f64,
+        num_bytes: num_bytes as f64,
+        num_indices: indices.len() as f64,
+    })
+}
+
+struct TableDescriptor {
+    name: String,
+    row_count: f64,
+    version: f64,
+    num_bytes: f64,
+    num_indices: f64,
+}
+
+impl TableDescriptor {
+    fn into_js<'a>(self, cx: &mut impl Context<'a>) -> JsResult<'a, JsObject> {
+        let obj = cx.empty_object();
+        let name = cx.string(self.name);
+        let row_count = cx.number(self.row_count);
+        let version = cx.number(self.version);
+        let num_bytes = cx.number(self.num_bytes);
+        let num_indices = cx.number(self.num_indices);
+        obj.set(cx, "name", name)?;
+        obj.set(cx, "rowCount", row_count)?;
+        obj.set(cx, "version", version)?;
+        obj.set(cx, "numBytes", num_bytes)?;
+        obj.set(cx, "numIndices", num_indices)?;
+        Ok(obj)
+    }
+}
+
+/// `connectionListTables(startAfter?, limit?)` — like `tableNames`, but
+/// resolves to an array of per-table descriptors instead of bare names, so
+/// callers can render a catalog without a round-trip per table.
+pub fn database_list_tables(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    let db = cx
+        .this()
+        .downcast_or_throw::<JsBox<crate::JsDatabase>, _>(&mut cx)?;
+
+    let start_after = cx
+        .argument_opt(0)
+        .filter(|arg| arg.is_a::<JsString, _>(&mut cx))
+        .and_then(|arg| arg.downcast_or_throw::<JsString, FunctionContext>(&mut cx).ok())
+        .map(|v| v.value(&mut cx));
+    let limit = cx
+        .argument_opt(1)
+        .and_then(|arg| arg.downcast::<JsNumber, _>(&mut cx).ok())
+        .map(|v| v.value(&mut cx) as u32);
+
+    let rt = crate::runtime(&mut cx)?;
+    let channel = cx.channel();
+    let database = db.database.clone();
+
+    let (deferred, promise) = cx.promise();
+    rt.spawn(async move {
+        let result: lancedb::Result<Vec<TableDescriptor>> = async {
+            let mut op = database.table_names();
+            if let Some(start_after) = start_after {
+                op = op.start_after(start_after);
+            }
+            if let Some(limit) = limit {
+                op = op.limit(limit);
+            }
+            let names = op.execute().await?;
+
+            stream::iter(names)
+                .map(|name| {
+                    let database = &database;
+                    async move { table_descriptor(database, &name).await }
+                })
+                // `buffered` (not `buffer_unordered`) keeps the descriptors
+                // in `tableNames` order, so the last name still works as the
+                // next page's `startAfter` cursor.
+                .buffered(DESCRIPTOR_CONCURRENCY)
+                .try_collect()
+                .await
+        }
+        .await;
+
+        deferred.settle_with(&channel, move |mut cx| {
+            let descriptors = result.or_throw(&mut cx)?;
+            let array = cx.empty_array();
+            for (i, descriptor) in descriptors.into_iter().enumerate() {
+                let js_descriptor = descriptor.into_js(&mut cx)?;
+                array.set(&mut cx, i as u32, js_descriptor)?;
+            }
+            Ok(array)
+        });
+    });
+    Ok(promise)
+}
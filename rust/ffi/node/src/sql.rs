@@ -0,0 +1,132 @@
+// Copyright 2024 Lance Developers.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::io::Cursor;
+use std::sync::Arc;
+
+use arrow::ipc::writer::StreamWriter;
+use async_trait::async_trait;
+use datafusion::catalog::schema::SchemaProvider;
+use datafusion::datasource::TableProvider;
+use datafusion::prelude::SessionContext;
+use futures::TryStreamExt;
+use lancedb::connection::Connection;
+use neon::prelude::*;
+
+use crate::error::ResultExt;
+
+/// A DataFusion `SchemaProvider` that resolves table names against a
+/// [`Connection`] on demand, so `run_sql` only opens the tables a query
+/// actually references instead of every table in the database, and opens
+/// them through the same `open_table` builder as everything else (so the
+/// connection's `storage_options`/`read_consistency_interval` apply here
+/// too).
+struct LanceSchemaProvider {
+    connection: Connection,
+}
+
+#[async_trait]
+impl SchemaProvider for LanceSchemaProvider {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn table_names(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    async fn table(&self, name: &str) -> datafusion::error::Result<Option<Arc<dyn TableProvider>>> {
+        let table = match self.connection.open_table(name).execute().await {
+            Ok(table) => table,
+            Err(_) => return Ok(None),
+        };
+        Ok(Some(table.dataset_table_provider().await?))
+    }
+
+    fn register_table(
+        &self,
+        name: String,
+        _table: Arc<dyn TableProvider>,
+    ) -> datafusion::error::Result<Option<Arc<dyn TableProvider>>> {
+        Err(datafusion::error::DataFusionError::Plan(format!(
+            "tables are resolved from the connection, `{name}` can't be registered directly"
+        )))
+    }
+
+    fn deregister_table(&self, _name: &str) -> datafusion::error::Result<Option<Arc<dyn TableProvider>>> {
+        Ok(None)
+    }
+
+    fn table_exist(&self, _name: &str) -> bool {
+        true
+    }
+}
+
+/// Run a SQL statement against `database`, resolving each referenced table
+/// lazily as a DataFusion `TableProvider` so the query can join, filter,
+/// and aggregate across tables instead of pulling each one out separately.
+///
+/// The full result is collected and returned as a single Arrow IPC stream
+/// buffer, which the JS side decodes with `apache-arrow`'s
+/// `RecordBatchStreamReader`, mirroring how `tableSearch` hands results back.
+pub(crate) async fn run_sql(database: &Connection, query: &str) -> lancedb::Result<Vec<u8>> {
+    let ctx = SessionContext::new();
+    ctx.catalog("datafusion")
+        .expect("default catalog is always present")
+        .register_schema(
+            "public",
+            Arc::new(LanceSchemaProvider {
+                connection: database.clone(),
+            }),
+        )?;
+
+    let df = ctx.sql(query).await?;
+    let schema = df.schema().as_arrow().clone().into();
+    let batches: Vec<_> = df.execute_stream().await?.try_collect().await?;
+
+    let mut buffer = Cursor::new(Vec::new());
+    {
+        let mut writer = StreamWriter::try_new(&mut buffer, &schema)?;
+        for batch in &batches {
+            writer.write(batch)?;
+        }
+        writer.finish()?;
+    }
+    Ok(buffer.into_inner())
+}
+
+pub fn database_sql(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    let db = cx
+        .this()
+        .downcast_or_throw::<JsBox<crate::JsDatabase>, _>(&mut cx)?;
+    let query = cx.argument::<JsString>(0)?.value(&mut cx);
+
+    let rt = crate::runtime(&mut cx)?;
+    let channel = cx.channel();
+    let database = db.database.clone();
+    let (deferred, promise) = cx.promise();
+
+    rt.spawn(async move {
+        let result = run_sql(&database, &query).await;
+
+        deferred.settle_with(&channel, move |mut cx| {
+            let bytes = result.or_throw(&mut cx)?;
+            let mut buffer = cx.buffer(bytes.len())?;
+            buffer.as_mut_slice(&mut cx).copy_from_slice(&bytes);
+            Ok(buffer)
+        });
+    });
+    Ok(promise)
+}